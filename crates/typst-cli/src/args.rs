@@ -459,6 +459,7 @@ display_possible_values!(DiagnosticFormat);
 #[derive(Debug, Copy, Clone, Eq, PartialEq, ValueEnum)]
 pub enum Feature {
     Html,
+    A11y,
 }
 
 display_possible_values!(Feature);
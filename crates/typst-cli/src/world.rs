@@ -117,6 +117,7 @@ impl SystemWorld {
                 .iter()
                 .map(|&feature| match feature {
                     Feature::Html => typst::Feature::Html,
+                    Feature::A11y => typst::Feature::A11y,
                 })
                 .collect();
 
@@ -5,6 +5,7 @@ mod complete;
 mod definition;
 mod jump;
 mod matchers;
+mod structure;
 mod tooltip;
 mod utils;
 
@@ -13,6 +14,7 @@ pub use self::complete::{autocomplete, Completion, CompletionKind};
 pub use self::definition::{definition, Definition};
 pub use self::jump::{jump_from_click, jump_from_cursor, Jump};
 pub use self::matchers::{deref_target, named_items, DerefTarget, NamedItem};
+pub use self::structure::{document_structure, StructureKind, StructureNode};
 pub use self::tooltip::{tooltip, Tooltip};
 
 use ecow::EcoString;
@@ -0,0 +1,157 @@
+use ecow::EcoString;
+use typst::foundations::{Content, NativeElement, Smart, StyleChain};
+use typst::layout::PagedDocument;
+use typst::model::{FigureElem, FigureKind, HeadingElem};
+use typst::syntax::Span;
+use typst::text::RawElem;
+
+/// A node in the structure tree of a document.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StructureNode {
+    /// What kind of element the node stands for.
+    pub kind: StructureKind,
+    /// A short description of the element, e.g. the text of a heading.
+    pub name: EcoString,
+    /// The span of the element in the source.
+    pub span: Span,
+    /// The nodes nested within this one.
+    pub children: Vec<StructureNode>,
+}
+
+/// A kind of structure node.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum StructureKind {
+    /// A heading.
+    Heading,
+    /// A figure.
+    Figure,
+    /// A figure containing a code block.
+    Code,
+}
+
+/// Build a tree of the headings and figures in a document, e.g. for folding
+/// and navigation in an editor.
+///
+/// Headings are nested according to their level and figures are placed below
+/// the closest preceding heading. Elements without a span in the source are
+/// omitted.
+pub fn document_structure(document: &PagedDocument) -> Vec<StructureNode> {
+    let mut roots = vec![];
+    let mut stack: Vec<(usize, StructureNode)> = vec![];
+
+    for elem in document.introspector.all() {
+        if elem.span().is_detached() {
+            continue;
+        }
+
+        let (level, node) = if let Some(heading) = elem.to_packed::<HeadingElem>() {
+            let level = heading.resolve_level(StyleChain::default()).get();
+            (Some(level), leaf(StructureKind::Heading, heading.body.plain_text(), elem))
+        } else if let Some(figure) = elem.to_packed::<FigureElem>() {
+            // The kind was resolved from the body's contents during synthesis.
+            let code = Smart::Custom(FigureKind::Elem(RawElem::elem()));
+            let kind = if figure.kind(StyleChain::default()) == code {
+                StructureKind::Code
+            } else {
+                StructureKind::Figure
+            };
+            let name = match figure.caption(StyleChain::default()) {
+                Some(caption) => caption.body.plain_text(),
+                None => EcoString::new(),
+            };
+            (None, leaf(kind, name, elem))
+        } else {
+            continue;
+        };
+
+        let Some(level) = level else {
+            match stack.last_mut() {
+                Some((_, parent)) => parent.children.push(node),
+                None => roots.push(node),
+            }
+            continue;
+        };
+
+        while stack.last().is_some_and(|&(prev, _)| prev >= level) {
+            close(&mut stack, &mut roots);
+        }
+
+        stack.push((level, node));
+    }
+
+    while !stack.is_empty() {
+        close(&mut stack, &mut roots);
+    }
+
+    roots
+}
+
+/// Create a node without children for an element.
+fn leaf(kind: StructureKind, name: EcoString, elem: &Content) -> StructureNode {
+    StructureNode { kind, name, span: elem.span(), children: vec![] }
+}
+
+/// Pop the innermost open heading and attach it to its parent.
+fn close(stack: &mut Vec<(usize, StructureNode)>, roots: &mut Vec<StructureNode>) {
+    let Some((_, node)) = stack.pop() else { return };
+    match stack.last_mut() {
+        Some((_, parent)) => parent.children.push(node),
+        None => roots.push(node),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Borrow;
+    use std::fmt::Write;
+
+    use super::{document_structure, StructureKind, StructureNode};
+    use crate::tests::WorldLike;
+
+    #[track_caller]
+    fn test(world: impl WorldLike, expected: &str) {
+        let world = world.acquire();
+        let world = world.borrow();
+        let doc = typst::compile(world).output.unwrap();
+        let mut out = String::new();
+        print(&mut out, &document_structure(&doc));
+        assert_eq!(out, expected);
+    }
+
+    fn print(out: &mut String, nodes: &[StructureNode]) {
+        for (i, node) in nodes.iter().enumerate() {
+            if i > 0 {
+                out.push_str(", ");
+            }
+            if node.kind == StructureKind::Code {
+                out.push('`');
+            }
+            write!(out, "{}", node.name).unwrap();
+            if !node.children.is_empty() {
+                out.push('(');
+                print(out, &node.children);
+                out.push(')');
+            }
+        }
+    }
+
+    #[test]
+    fn test_structure_nesting() {
+        test("= A\n== B\n=== C\n== D\n= E", "A(B(C), D), E");
+        test("== A\n= B\n=== C", "A, B(C)");
+    }
+
+    #[test]
+    fn test_structure_figures() {
+        test(
+            "#figure([], caption: [X])\n= A\n#figure(```rs fn f() {}```, caption: [Y])",
+            "X, A(`Y)",
+        );
+    }
+
+    #[test]
+    fn test_structure_figures_wrapped_code() {
+        test("#figure([Code: ```rs fn f() {}```], caption: [Z])", "`Z");
+        test("#figure(block(```rs fn f() {}```), caption: [Z])", "`Z");
+    }
+}
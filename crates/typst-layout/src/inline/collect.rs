@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 use typst_library::diag::warning;
 use typst_library::foundations::{Packed, Resolve};
 use typst_library::introspection::{SplitLocator, Tag, TagElem};
@@ -154,10 +156,16 @@ pub fn collect<'a>(
                     }
                 }
 
+                let text = if TextElem::smart_dashes_in(styles) {
+                    Cow::Owned(smart_dashes(&elem.text))
+                } else {
+                    Cow::Borrowed(elem.text.as_str())
+                };
+
                 if let Some(case) = TextElem::case_in(styles) {
-                    full.push_str(&case.apply(&elem.text));
+                    full.push_str(&case.apply(&text));
                 } else {
-                    full.push_str(&elem.text);
+                    full.push_str(&text);
                 }
 
                 if dir != config.dir {
@@ -329,3 +337,56 @@ impl SpanMapper {
         (Span::detached(), 0)
     }
 }
+
+/// Replaces triple hyphens with em dashes, double hyphens with en dashes, and a
+/// hyphen in a number range like `10-24` with an en dash.
+///
+/// Numbers with multiple hyphens, like the date `2024-01-15`, are kept as is.
+fn smart_dashes(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut out = String::with_capacity(text.len());
+    let mut i = 0;
+
+    while i < chars.len() {
+        match chars[i] {
+            '-' if chars[i..].starts_with(&['-', '-', '-']) => {
+                out.push('\u{2014}');
+                i += 3;
+            }
+            '-' if chars[i..].starts_with(&['-', '-']) => {
+                out.push('\u{2013}');
+                i += 2;
+            }
+            c if c.is_ascii_digit() => {
+                // Find the end of the run of digit groups joined by single
+                // hyphens.
+                let mut end = i;
+                let mut hyphens = 0;
+                loop {
+                    while chars.get(end).is_some_and(char::is_ascii_digit) {
+                        end += 1;
+                    }
+                    if chars.get(end) == Some(&'-')
+                        && chars.get(end + 1).is_some_and(char::is_ascii_digit)
+                    {
+                        hyphens += 1;
+                        end += 1;
+                    } else {
+                        break;
+                    }
+                }
+
+                for &c in &chars[i..end] {
+                    out.push(if c == '-' && hyphens == 1 { '\u{2013}' } else { c });
+                }
+                i = end;
+            }
+            c => {
+                out.push(c);
+                i += 1;
+            }
+        }
+    }
+
+    out
+}
@@ -1,3 +1,5 @@
+use typst_library::diag::warning;
+use typst_library::foundations::Repr;
 use typst_library::introspection::SplitLocator;
 use typst_utils::Numeric;
 
@@ -26,6 +28,10 @@ pub fn finalize(
         region.x
     };
 
+    if let Some(tolerance) = p.config.overfull_tolerance {
+        report_overfull(engine, lines, region.x - p.config.hanging_indent, tolerance);
+    }
+
     // Stack the lines into one frame per region.
     lines
         .iter()
@@ -33,3 +39,36 @@ pub fn finalize(
         .collect::<SourceResult<_>>()
         .map(Fragment::frames)
 }
+
+/// Warns about lines that extend beyond the available width by more than the
+/// tolerance.
+fn report_overfull(engine: &mut Engine, lines: &[Line], available: Abs, tolerance: Abs) {
+    if !available.is_finite() {
+        return;
+    }
+
+    for line in lines {
+        let overflow = line.width - available;
+        if overflow <= tolerance {
+            continue;
+        }
+
+        // Point at the start of the line's text, if there is any.
+        let Some(span) = line
+            .items
+            .iter()
+            .find_map(Item::text)
+            .and_then(|text| text.glyphs.first())
+            .map(|glyph| glyph.span.0)
+        else {
+            continue;
+        };
+
+        engine.sink.warn(warning!(
+            span,
+            "line exceeds the available width by {}",
+            overflow.repr();
+            hint: "enable hyphenation or rephrase the text so that it can be broken"
+        ));
+    }
+}
@@ -249,6 +249,7 @@ fn linebreak_optimized_bounded<'a>(
                 width,
                 &pred.line,
                 &attempt,
+                end,
                 breakpoint,
                 unbreakable,
             );
@@ -464,8 +465,16 @@ fn linebreak_optimized_approximate(
         let Entry { end, breakpoint, unbreakable, .. } = table[idx];
 
         let attempt = line(engine, p, start..end, breakpoint, Some(&pred));
-        let (ratio, line_cost) =
-            ratio_and_cost(p, metrics, width, &pred, &attempt, breakpoint, unbreakable);
+        let (ratio, line_cost) = ratio_and_cost(
+            p,
+            metrics,
+            width,
+            &pred,
+            &attempt,
+            end,
+            breakpoint,
+            unbreakable,
+        );
 
         // If approximation produces a valid layout without too much shrinking,
         // exact layout is guaranteed to find the same layout. If, however, the
@@ -493,6 +502,7 @@ fn ratio_and_cost(
     available_width: Abs,
     pred: &Line,
     attempt: &Line,
+    end: usize,
     breakpoint: Breakpoint,
     unbreakable: bool,
 ) -> (f64, Cost) {
@@ -505,12 +515,20 @@ fn ratio_and_cost(
         attempt.justifiables(),
     );
 
+    // A last line that falls short of the minimum fill is penalized like a
+    // runt. Lines before forced line breaks within the paragraph are not
+    // affected. The first line is exempt because there is nothing before it
+    // that could be pulled down into it.
+    let underfull = end == p.text.len()
+        && !pred.items.is_empty()
+        && attempt.width < metrics.last_line_min_fill.of(available_width);
+
     let cost = raw_cost(
         metrics,
         breakpoint,
         ratio,
         attempt.justify,
-        unbreakable,
+        unbreakable || underfull,
         pred.dash.is_some() && attempt.dash.is_some(),
         false,
     );
@@ -850,6 +868,7 @@ struct CostMetrics {
     approx_hyphen_width: Abs,
     hyph_cost: Cost,
     runt_cost: Cost,
+    last_line_min_fill: Ratio,
 }
 
 impl CostMetrics {
@@ -864,6 +883,7 @@ impl CostMetrics {
             // Costs.
             hyph_cost: DEFAULT_HYPH_COST * p.config.costs.hyphenation().get(),
             runt_cost: DEFAULT_RUNT_COST * p.config.costs.runt().get(),
+            last_line_min_fill: p.config.last_line_min_fill,
         }
     }
 
@@ -15,7 +15,9 @@ use typst_library::diag::SourceResult;
 use typst_library::engine::{Engine, Route, Sink, Traced};
 use typst_library::foundations::{Packed, Resolve, Smart, StyleChain};
 use typst_library::introspection::{Introspector, Locator, LocatorLink, SplitLocator};
-use typst_library::layout::{Abs, AlignElem, Dir, FixedAlignment, Fragment, Size};
+use typst_library::layout::{
+    Abs, AlignElem, Dir, FixedAlignment, Fragment, PageElem, Ratio, Size,
+};
 use typst_library::model::{
     EnumElem, FirstLineIndent, Linebreaks, ListElem, ParElem, ParLine, ParLineMarker,
     TermsElem,
@@ -116,6 +118,7 @@ fn layout_par_impl(
             linebreaks: elem.linebreaks(styles),
             first_line_indent: elem.first_line_indent(styles),
             hanging_indent: elem.hanging_indent(styles),
+            last_line_min_fill: elem.last_line_min_fill(styles),
         },
     )
 }
@@ -142,6 +145,7 @@ pub fn layout_inline<'a>(
             linebreaks: ParElem::linebreaks_in(shared),
             first_line_indent: ParElem::first_line_indent_in(shared),
             hanging_indent: ParElem::hanging_indent_in(shared),
+            last_line_min_fill: ParElem::last_line_min_fill_in(shared),
         },
     )
 }
@@ -218,6 +222,8 @@ fn configuration(
         } else {
             Abs::zero()
         },
+        last_line_min_fill: base.last_line_min_fill,
+        overfull_tolerance: PageElem::overfull_tolerance_in(shared),
         numbering_marker: ParLine::numbering_in(shared).map(|numbering| {
             Packed::new(ParLineMarker::new(
                 numbering,
@@ -261,6 +267,7 @@ struct ConfigBase {
     linebreaks: Smart<Linebreaks>,
     first_line_indent: FirstLineIndent,
     hanging_indent: Abs,
+    last_line_min_fill: Ratio,
 }
 
 /// Shared configuration for the whole inline layout.
@@ -273,6 +280,12 @@ struct Config {
     first_line_indent: Abs,
     /// The indent that all but the first line of a paragraph should have.
     hanging_indent: Abs,
+    /// How much of the width the last line of a paragraph should fill at
+    /// least.
+    last_line_min_fill: Ratio,
+    /// How far a line may overflow the available width before it is
+    /// reported.
+    overfull_tolerance: Option<Abs>,
     /// Configuration for line numbering.
     numbering_marker: Option<Packed<ParLineMarker>>,
     /// The resolved horizontal alignment.
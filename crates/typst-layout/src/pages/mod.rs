@@ -5,16 +5,17 @@ mod finalize;
 mod run;
 
 use comemo::{Tracked, TrackedMut};
-use typst_library::diag::SourceResult;
+use typst_library::diag::{warning, SourceResult};
 use typst_library::engine::{Engine, Route, Sink, Traced};
 use typst_library::foundations::{Content, StyleChain};
 use typst_library::introspection::{
     Introspector, Locator, ManualPageCounter, SplitLocator, TagElem,
 };
 use typst_library::layout::{FrameItem, Page, PagedDocument, Point};
-use typst_library::model::DocumentInfo;
+use typst_library::model::{DocumentInfo, PageCount};
 use typst_library::routines::{Arenas, Pair, RealizationKind, Routines};
 use typst_library::World;
+use typst_syntax::Span;
 
 use self::collect::{collect, Item};
 use self::finalize::finalize;
@@ -83,7 +84,8 @@ fn layout_document_impl(
         styles,
     )?;
 
-    let pages = layout_pages(&mut engine, &mut children, &mut locator, styles)?;
+    let pages =
+        layout_pages(&mut engine, &mut children, &mut locator, styles, info.page_count)?;
     let introspector = Introspector::paged(&pages);
 
     Ok(PagedDocument { pages, info, introspector })
@@ -95,6 +97,7 @@ fn layout_pages<'a>(
     children: &'a mut [Pair<'a>],
     locator: &mut SplitLocator<'a>,
     styles: StyleChain<'a>,
+    page_count: PageCount,
 ) -> SourceResult<Vec<Page>> {
     // Slice up the children into logical parts.
     let items = collect(children, locator, styles);
@@ -115,12 +118,14 @@ fn layout_pages<'a>(
     let mut pages = vec![];
     let mut tags = vec![];
     let mut counter = ManualPageCounter::new();
+    let mut last = styles;
 
     // Collect and finalize the runs, handling things like page parity and tags
     // between pages.
     for item in &items {
         match item {
-            Item::Run(..) => {
+            Item::Run(_, initial, _) => {
+                last = *initial;
                 let layouted = runs.next().unwrap()?;
                 for layouted in layouted {
                     let page = finalize(engine, &mut counter, &mut tags, layouted)?;
@@ -128,6 +133,7 @@ fn layout_pages<'a>(
                 }
             }
             Item::Parity(parity, initial, locator) => {
+                last = *initial;
                 if !parity.matches(pages.len()) {
                     continue;
                 }
@@ -147,12 +153,36 @@ fn layout_pages<'a>(
         }
     }
 
-    // Add the remaining tags to the very end of the last page.
+    // Add the remaining tags to the very end of the last page. This
+    // happens before padding so that they end up on the last page with content.
     if !tags.is_empty() {
         let last = pages.last_mut().unwrap();
         let pos = Point::with_y(last.frame.height());
         last.frame
-            .push_multiple(tags.into_iter().map(|tag| (pos, FrameItem::Tag(tag))));
+            .push_multiple(tags.drain(..).map(|tag| (pos, FrameItem::Tag(tag))));
+    }
+
+    // Pad the document with blank pages until the page count is a multiple of
+    // the requested number.
+    if let Some(multiple) = page_count.multiple_of {
+        while pages.len() % multiple.get() != 0 {
+            let layouted = layout_blank_page(engine, locator.next(&()), last)?;
+            let page = finalize(engine, &mut counter, &mut tags, layouted)?;
+            pages.push(page);
+        }
+    }
+
+    if let Some(max) = page_count.max {
+        if pages.len() > max.get() {
+            engine.sink.warn(warning!(
+                Span::detached(),
+                "document has {} pages, more than the maximum of {}",
+                pages.len(),
+                max;
+                hint: "this maximum comes from `document(page-count: ..)` \
+                       and does not prevent export"
+            ));
+        }
     }
 
     Ok(pages)
@@ -133,6 +133,11 @@ pub struct PageElem {
     /// The values for `left` and `right` are mutually exclusive with
     /// the values for `inside` and `outside`.
     ///
+    /// Which side is inside and which is outside depends on the physical page
+    /// number. This is resolved once all pages are known, including the blank
+    /// pages inserted by [pagebreaks to a specific parity]($pagebreak.to), so
+    /// mirrored margins stay correct for duplex printing.
+    ///
     /// ```example
     /// #set page(
     ///  width: 3cm,
@@ -278,6 +283,37 @@ pub struct PageElem {
     ///
     /// #lorem(19)
     /// ```
+    ///
+    /// Since the header is laid out separately for each page, it can use
+    /// [context] to show information about the page it is on. For instance,
+    /// a running head displaying the current section can be built by querying
+    /// for headings. Here, the first heading starting on a page takes
+    /// precedence over the one that was still active at the end of the
+    /// previous page.
+    ///
+    /// ```example
+    /// >>> #set page(height: 120pt)
+    /// #set page(header: context {
+    ///   let page = here().page()
+    ///   let starting = query(heading)
+    ///     .filter(it => it.location().page() == page)
+    ///   let previous = query(selector(heading).before(here()))
+    ///   let current = if starting != () {
+    ///     starting.first()
+    ///   } else if previous != () {
+    ///     previous.last()
+    ///   }
+    ///   if current != none {
+    ///     align(right, emph(current.body))
+    ///   }
+    /// })
+    ///
+    /// = Introduction
+    /// #lorem(15)
+    ///
+    /// = Methods
+    /// #lorem(15)
+    /// ```
     #[borrowed]
     #[ghost]
     pub header: Smart<Option<Content>>,
@@ -361,6 +397,24 @@ pub struct PageElem {
     #[ghost]
     pub foreground: Option<Content>,
 
+    /// How far a line of text may extend beyond the available width before a
+    /// warning is emitted.
+    ///
+    /// When set to `{none}` (default), overfull lines are not reported. Note
+    /// that this only concerns lines that could not be broken any further,
+    /// e.g. because they contain a long word or URL. Content that
+    /// intentionally extends into the margin, like a [`place`]d element or a
+    /// block with negative [padding]($pad), is not affected.
+    ///
+    /// ```example
+    /// #set page(width: 100pt, overfull-tolerance: 1pt)
+    /// #set text(hyphenate: false)
+    /// Supercalifragilistic
+    /// ```
+    #[resolve]
+    #[ghost]
+    pub overfull_tolerance: Option<Length>,
+
     /// The contents of the page(s).
     ///
     /// Multiple pages will be created if the content does not fit on a single
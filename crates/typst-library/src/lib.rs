@@ -235,6 +235,7 @@ impl FromIterator<Feature> for Features {
 #[non_exhaustive]
 pub enum Feature {
     Html,
+    A11y,
 }
 
 /// A group of related standard library definitions.
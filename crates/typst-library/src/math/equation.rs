@@ -15,7 +15,7 @@ use crate::layout::{
     VAlignment,
 };
 use crate::math::{MathSize, MathVariant};
-use crate::model::{Numbering, Outlinable, ParLine, Refable, Supplement};
+use crate::model::{Numbering, Outlinable, ParLine, RefElem, Refable, Supplement};
 use crate::text::{FontFamily, FontList, FontWeight, LocalName, TextElem};
 
 /// A mathematical equation.
@@ -66,6 +66,27 @@ pub struct EquationElem {
     #[borrowed]
     pub numbering: Option<Numbering>,
 
+    /// Whether only equations that are [referenced]($ref) somewhere in the
+    /// document should be numbered.
+    ///
+    /// Unreferenced equations are then neither numbered nor counted, so the
+    /// numbers of referenced ones stay consecutive. References may also come
+    /// after the equation they refer to.
+    ///
+    /// ```example
+    /// #set math.equation(
+    ///   numbering: "(1)",
+    ///   number-referenced-only: true,
+    /// )
+    ///
+    /// $ a^2 + b^2 = c^2 $
+    /// $ e^(i pi) + 1 = 0 $ <euler>
+    ///
+    /// See @euler.
+    /// ```
+    #[default(false)]
+    pub number_referenced_only: bool,
+
     /// The alignment of the equation numbering.
     ///
     /// By default, the alignment is `{end + horizon}`. For the horizontal
@@ -161,10 +182,27 @@ impl Synthesize for Packed<EquationElem> {
         };
 
         self.push_supplement(Smart::Custom(Some(Supplement::Content(supplement))));
+
+        if self.number_referenced_only(styles) && !self.is_referenced(engine) {
+            self.push_numbering(None);
+        }
+
         Ok(())
     }
 }
 
+impl Packed<EquationElem> {
+    /// Whether any reference in the document targets this equation.
+    fn is_referenced(&self, engine: &Engine) -> bool {
+        let Some(label) = self.label() else { return false };
+        engine
+            .introspector
+            .query(&RefElem::elem().select())
+            .iter()
+            .any(|elem| elem.to_packed::<RefElem>().unwrap().target == label)
+    }
+}
+
 impl Show for Packed<EquationElem> {
     fn show(&self, engine: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
         if self.block(styles) {
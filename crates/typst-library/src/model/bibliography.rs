@@ -23,8 +23,8 @@ use crate::diag::{bail, error, At, FileError, HintedStrResult, SourceResult, Str
 use crate::engine::{Engine, Sink};
 use crate::foundations::{
     elem, Bytes, CastInfo, Content, Derived, FromValue, IntoValue, Label, NativeElement,
-    OneOrMultiple, Packed, Reflect, Scope, Show, ShowSet, Smart, StyleChain, Styles,
-    Synthesize, Value,
+    OneOrMultiple, Packed, Reflect, Scope, Selector, Show, ShowSet, Smart, StyleChain,
+    Styles, Synthesize, Value,
 };
 use crate::introspection::{Introspector, Locatable, Location};
 use crate::layout::{
@@ -59,6 +59,11 @@ use crate::World;
 /// [citation]($cite) function (`[#cite(<key>)]`). The bibliography will only
 /// show entries for works that were referenced in the document.
 ///
+/// A document may also contain multiple bibliographies, for instance one at
+/// the end of each chapter. Each bibliography then covers the citations
+/// between the previous bibliography and itself. Citations after the last
+/// bibliography are covered by that last one.
+///
 /// # Styles
 /// Typst offers a wide selection of built-in
 /// [citation and bibliography styles]($bibliography.style). Beyond those, you
@@ -153,18 +158,23 @@ pub struct BibliographyElem {
 }
 
 impl BibliographyElem {
-    /// Find the document's bibliography.
-    pub fn find(introspector: Tracked<Introspector>) -> StrResult<Packed<Self>> {
-        let query = introspector.query(&Self::elem().select());
-        let mut iter = query.iter();
-        let Some(elem) = iter.next() else {
+    /// Find the bibliography that covers the citation group at the given
+    /// location.
+    ///
+    /// This is the first bibliography after the citation group, or the last
+    /// one if there is none after it.
+    pub fn find(
+        introspector: Tracked<Introspector>,
+        location: Location,
+    ) -> StrResult<Packed<Self>> {
+        let selector = Self::elem().select();
+        let query = introspector.query(&selector);
+        if query.is_empty() {
             bail!("the document does not contain a bibliography");
-        };
-
-        if iter.next().is_some() {
-            bail!("multiple bibliographies are not yet supported");
         }
 
+        let index = introspector.query_count_before(&selector, location);
+        let elem = &query[index.min(query.len() - 1)];
         Ok(elem.to_packed::<Self>().unwrap().clone())
     }
 
@@ -220,7 +230,7 @@ impl Show for Packed<BibliographyElem> {
             );
         }
 
-        let works = Works::generate(engine).at(span)?;
+        let works = Works::generate(engine, self.location().unwrap()).at(span)?;
         let references = works
             .references
             .as_ref()
@@ -536,9 +546,9 @@ impl IntoValue for CslSource {
 }
 
 /// Fully formatted citations and references, generated once (through
-/// memoization) for the whole document. This setup is necessary because
-/// citation formatting is inherently stateful and we need access to all
-/// citations to do it.
+/// memoization) for each bibliography in the document. This setup is necessary
+/// because citation formatting is inherently stateful and we need access to all
+/// citations covered by the bibliography to do it.
 pub(super) struct Works {
     /// Maps from the location of a citation group to its rendered content.
     pub citations: HashMap<Location, SourceResult<Content>>,
@@ -550,9 +560,10 @@ pub(super) struct Works {
 }
 
 impl Works {
-    /// Generate all citations and the whole bibliography.
-    pub fn generate(engine: &Engine) -> StrResult<Arc<Works>> {
-        Self::generate_impl(engine.routines, engine.world, engine.introspector)
+    /// Generate the citations and references of the bibliography at the given
+    /// location.
+    pub fn generate(engine: &Engine, location: Location) -> StrResult<Arc<Works>> {
+        Self::generate_impl(engine.routines, engine.world, engine.introspector, location)
     }
 
     /// The internal implementation of [`Works::generate`].
@@ -561,8 +572,9 @@ impl Works {
         routines: &Routines,
         world: Tracked<dyn World + '_>,
         introspector: Tracked<Introspector>,
+        location: Location,
     ) -> StrResult<Arc<Works>> {
-        let mut generator = Generator::new(routines, world, introspector)?;
+        let mut generator = Generator::new(routines, world, introspector, location)?;
         let rendered = generator.drive();
         let works = generator.display(&rendered)?;
         Ok(Arc::new(works))
@@ -575,10 +587,10 @@ struct Generator<'a> {
     routines: &'a Routines,
     /// The world that is used to evaluate mathematical material in citations.
     world: Tracked<'a, dyn World + 'a>,
-    /// The document's bibliography.
+    /// The bibliography to generate.
     bibliography: Packed<BibliographyElem>,
-    /// The document's citation groups.
-    groups: EcoVec<Content>,
+    /// The citation groups covered by the bibliography.
+    groups: Vec<Content>,
     /// Details about each group that are accumulated while driving hayagriva's
     /// bibliography driver and needed when processing hayagriva's output.
     infos: Vec<GroupInfo>,
@@ -616,9 +628,21 @@ impl<'a> Generator<'a> {
         routines: &'a Routines,
         world: Tracked<'a, dyn World + 'a>,
         introspector: Tracked<Introspector>,
+        location: Location,
     ) -> StrResult<Self> {
-        let bibliography = BibliographyElem::find(introspector)?;
-        let groups = introspector.query(&CiteGroup::elem().select());
+        let Some(bibliography) = introspector.query_first(&Selector::Location(location))
+        else {
+            bail!("the document does not contain a bibliography");
+        };
+        let bibliography = bibliography.to_packed::<BibliographyElem>().unwrap().clone();
+        let groups = introspector
+            .query(&CiteGroup::elem().select())
+            .into_iter()
+            .filter(|group| {
+                BibliographyElem::find(introspector, group.location().unwrap())
+                    .is_ok_and(|elem| elem.location() == Some(location))
+            })
+            .collect();
         let infos = Vec::with_capacity(groups.len());
         Ok(Self {
             routines,
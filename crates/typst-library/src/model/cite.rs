@@ -8,7 +8,7 @@ use crate::foundations::{
 };
 use crate::introspection::Locatable;
 use crate::model::bibliography::Works;
-use crate::model::{CslSource, CslStyle};
+use crate::model::{BibliographyElem, CslSource, CslStyle};
 use crate::text::{Lang, Region, TextElem};
 
 /// Cite a work from the bibliography.
@@ -109,6 +109,24 @@ pub struct CiteElem {
     #[borrowed]
     pub style: Smart<Derived<CslSource, CslStyle>>,
 
+    /// Whether a group of adjacent citations is kept together on one line.
+    ///
+    /// When enabled, a citation group like "[1, 2]" will never be broken
+    /// across lines. If the group is too wide to fit on any line, it
+    /// overflows instead. This property is read from the first citation of a
+    /// group.
+    ///
+    /// ```example
+    /// #set page(width: 150pt)
+    /// #set cite(group-unbreakable: true)
+    /// This was already noted by
+    /// pirates long ago. @arrgh @netwok
+    ///
+    /// #bibliography("works.bib")
+    /// ```
+    #[default(false)]
+    pub group_unbreakable: bool,
+
     /// The text language setting where the citation is.
     #[internal]
     #[synthesized]
@@ -166,7 +184,9 @@ impl Show for Packed<CiteGroup> {
     fn show(&self, engine: &mut Engine, _: StyleChain) -> SourceResult<Content> {
         let location = self.location().unwrap();
         let span = self.span();
-        Works::generate(engine)
+        let bibliography =
+            BibliographyElem::find(engine.introspector, location).at(span)?;
+        Works::generate(engine, bibliography.location().unwrap())
             .at(span)?
             .citations
             .get(&location)
@@ -1,10 +1,12 @@
+use std::num::NonZeroUsize;
+
 use ecow::EcoString;
 
 use crate::diag::{bail, HintedStrResult, SourceResult};
 use crate::engine::Engine;
 use crate::foundations::{
-    cast, elem, Args, Array, Construct, Content, Datetime, Fields, OneOrMultiple, Smart,
-    StyleChain, Styles, Value,
+    cast, dict, elem, Args, Array, Construct, Content, Datetime, Dict, Fields,
+    OneOrMultiple, Smart, StyleChain, Styles, Value,
 };
 
 /// The root element of a document and its metadata.
@@ -58,6 +60,29 @@ pub struct DocumentElem {
     /// something other than `{auto}`.
     #[ghost]
     pub date: Smart<Option<Datetime>>,
+
+    /// Constraints on the number of pages in the document.
+    ///
+    /// - `multiple-of`: If given, blank pages are added at the end of the
+    ///   document until its page count is a multiple of this number. This is
+    ///   useful for booklets that are bound in signatures of, e.g., four pages.
+    /// - `max`: If given, Typst warns when the document has more pages than
+    ///   this. The document is still exported in full.
+    ///
+    /// The padding happens after all other pages, including those inserted
+    /// by [page breaks]($pagebreak.to) to reach a specific page parity. The
+    /// blank pages use the page configuration of the last page.
+    ///
+    /// ```example
+    /// #set document(page-count: (multiple-of: 4, max: 8))
+    /// #set page(width: 80pt, height: 40pt)
+    ///
+    /// First
+    /// #pagebreak()
+    /// Second
+    /// ```
+    #[ghost]
+    pub page_count: PageCount,
 }
 
 impl Construct for DocumentElem {
@@ -88,6 +113,35 @@ cast! {
     v: Array => Self(v.into_iter().map(Value::cast).collect::<HintedStrResult<_>>()?),
 }
 
+/// Constraints on the number of pages in a document.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct PageCount {
+    /// The number the page count should be a multiple of.
+    pub multiple_of: Option<NonZeroUsize>,
+    /// The maximum number of pages.
+    pub max: Option<NonZeroUsize>,
+}
+
+cast! {
+    PageCount,
+    self => Value::Dict(self.into()),
+    mut dict: Dict => {
+        let multiple_of = dict.take("multiple-of").ok().map(|v| v.cast()).transpose()?;
+        let max = dict.take("max").ok().map(|v| v.cast()).transpose()?;
+        dict.finish(&["multiple-of", "max"])?;
+        Self { multiple_of, max }
+    },
+}
+
+impl From<PageCount> for Dict {
+    fn from(count: PageCount) -> Self {
+        dict! {
+            "multiple-of" => count.multiple_of,
+            "max" => count.max,
+        }
+    }
+}
+
 /// Details about the document.
 #[derive(Debug, Default, Clone, PartialEq, Hash)]
 pub struct DocumentInfo {
@@ -101,6 +155,8 @@ pub struct DocumentInfo {
     pub keywords: Vec<EcoString>,
     /// The document's creation date.
     pub date: Smart<Option<Datetime>>,
+    /// Constraints on the document's page count.
+    pub page_count: PageCount,
 }
 
 impl DocumentInfo {
@@ -127,5 +183,8 @@ impl DocumentInfo {
         if has(<DocumentElem as Fields>::Enum::Date) {
             self.date = DocumentElem::date_in(chain);
         }
+        if has(<DocumentElem as Fields>::Enum::PageCount) {
+            self.page_count = DocumentElem::page_count_in(chain);
+        }
     }
 }
@@ -156,6 +156,26 @@ pub struct EnumElem {
     #[default(false)]
     pub reversed: bool,
 
+    /// Whether the numbering should continue from a preceding enumeration
+    /// that is only separated from this one by figures or other blocks.
+    ///
+    /// Normally, an enumeration that is interrupted by a figure restarts at
+    /// one. With this enabled, it picks up where the previous one left off.
+    /// A paragraph or heading in between still starts a fresh enumeration,
+    /// as does an explicit [`start`]($enum.start).
+    ///
+    /// ```example
+    /// #set enum(continue-over-blocks: true)
+    /// + Mix the dough.
+    /// + Let it rest.
+    ///
+    /// #figure(rect[Dough])
+    ///
+    /// + Bake it.
+    /// ```
+    #[default(false)]
+    pub continue_over_blocks: bool,
+
     /// The indentation of each item.
     #[resolve]
     pub indent: Length,
@@ -273,6 +293,17 @@ impl Show for Packed<EnumElem> {
 #[elem(name = "item", title = "Numbered List Item")]
 pub struct EnumItem {
     /// The item's number.
+    ///
+    /// If set, the following items without an explicit number continue
+    /// counting from it, even if it is lower than the number the item would
+    /// otherwise have had.
+    ///
+    /// ```example
+    /// + First
+    /// + Second
+    /// 5. Fifth
+    /// + Sixth
+    /// ```
     #[positional]
     pub number: Option<u64>,
 
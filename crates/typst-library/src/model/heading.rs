@@ -3,11 +3,11 @@ use std::num::NonZeroUsize;
 use ecow::eco_format;
 use typst_utils::{Get, NonZeroExt};
 
-use crate::diag::{warning, SourceResult};
+use crate::diag::{bail, warning, SourceResult};
 use crate::engine::Engine;
 use crate::foundations::{
-    elem, Content, NativeElement, Packed, Resolve, Show, ShowSet, Smart, StyleChain,
-    Styles, Synthesize, TargetElem,
+    elem, Cast, Content, NativeElement, Packed, Resolve, Show, ShowSet, Smart,
+    StyleChain, Styles, Synthesize, TargetElem,
 };
 use crate::html::{attr, tag, HtmlElem};
 use crate::introspection::{
@@ -96,6 +96,27 @@ pub struct HeadingElem {
     #[default(0)]
     pub offset: usize,
 
+    /// The maximum level a heading may have.
+    ///
+    /// Headings whose level exceeds this limit, whether written directly or
+    /// produced by a show rule, are reported and handled according to the
+    /// [`level-overflow`]($heading.level-overflow) property. By default, there
+    /// is no maximum level.
+    ///
+    /// ```example
+    /// #set heading(max-level: 2)
+    ///
+    /// = Level 1
+    /// == Level 2
+    /// === Clamped to level 2
+    /// ```
+    pub max_level: Option<NonZeroUsize>,
+
+    /// How to handle a heading whose level exceeds the
+    /// [`max-level`]($heading.max-level).
+    #[default(LevelOverflow::Warn)]
+    pub level_overflow: LevelOverflow,
+
     /// How to number the heading. Accepts a
     /// [numbering pattern or function]($numbering).
     ///
@@ -187,6 +208,12 @@ pub struct HeadingElem {
 
 impl HeadingElem {
     pub fn resolve_level(&self, styles: StyleChain) -> NonZeroUsize {
+        let level = self.unbounded_level(styles);
+        self.max_level(styles).map_or(level, |max| level.min(max))
+    }
+
+    /// The level of the heading, disregarding the maximum level.
+    fn unbounded_level(&self, styles: StyleChain) -> NonZeroUsize {
         self.level(styles).unwrap_or_else(|| {
             NonZeroUsize::new(self.offset(styles) + self.depth(styles).get())
                 .expect("overflow to 0 on NoneZeroUsize + usize")
@@ -208,13 +235,42 @@ impl Synthesize for Packed<HeadingElem> {
             }
         };
 
+        let span = self.span();
         let elem = self.as_mut();
+        let level = elem.unbounded_level(styles);
+        if let Some(max) = elem.max_level(styles).filter(|&max| level > max) {
+            match elem.level_overflow(styles) {
+                LevelOverflow::Warn => engine.sink.warn(warning!(
+                    span,
+                    "heading of level {} exceeds the maximum level of {}",
+                    level, max;
+                    hint: "the heading was clamped to level {}", max
+                )),
+                LevelOverflow::Error => bail!(
+                    span,
+                    "heading of level {} exceeds the maximum level of {}",
+                    level, max;
+                    hint: "you may want to restructure your document so that \
+                           it doesn't contain deep headings"
+                ),
+            }
+        }
+
         elem.push_level(Smart::Custom(elem.resolve_level(styles)));
         elem.push_supplement(Smart::Custom(Some(Supplement::Content(supplement))));
         Ok(())
     }
 }
 
+/// How to handle a heading that is nested too deeply.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum LevelOverflow {
+    /// Emit a warning and clamp the heading to the maximum level.
+    Warn,
+    /// Fail with an error.
+    Error,
+}
+
 impl Show for Packed<HeadingElem> {
     #[typst_macros::time(name = "heading", span = self.span())]
     fn show(&self, engine: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
@@ -98,6 +98,17 @@ pub struct ListElem {
     pub marker: ListMarker,
 
     /// The indent of each item.
+    ///
+    /// The indent is applied at the start of the line. In right-to-left text,
+    /// both the indent and the marker are thus on the right side. The direction
+    /// is determined by the text direction that is active for the list itself,
+    /// so a list can have a different direction than the surrounding text.
+    ///
+    /// ```example
+    /// #set text(lang: "ar")
+    /// - مرحبا
+    /// - بالعالم
+    /// ```
     #[resolve]
     pub indent: Length,
 
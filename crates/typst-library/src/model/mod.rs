@@ -20,6 +20,7 @@ mod reference;
 mod strong;
 mod table;
 mod terms;
+mod todo;
 
 pub use self::bibliography::*;
 pub use self::cite::*;
@@ -39,6 +40,7 @@ pub use self::reference::*;
 pub use self::strong::*;
 pub use self::table::*;
 pub use self::terms::*;
+pub use self::todo::*;
 
 use crate::foundations::Scope;
 
@@ -63,6 +65,7 @@ pub fn define(global: &mut Scope) {
     global.define_elem::<TermsElem>();
     global.define_elem::<EmphElem>();
     global.define_elem::<StrongElem>();
+    global.define_elem::<TodoElem>();
     global.define_func::<numbering>();
     global.reset_category();
 }
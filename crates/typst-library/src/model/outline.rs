@@ -183,6 +183,29 @@ pub struct OutlineElem {
     ///   caption: [Experiment results],
     /// )
     /// ```
+    ///
+    /// By combining the target with [`after`]($selector.after) and
+    /// [`before`]($selector.before), you can restrict the outline to a part of
+    /// the document, for instance to create a small table of contents for a
+    /// single chapter. If no headings fall into that part, the outline is
+    /// empty.
+    ///
+    /// ```example
+    /// = Introduction <intro>
+    /// #outline(
+    ///   title: none,
+    ///   target: heading
+    ///     .where(level: 2)
+    ///     .after(<intro>)
+    ///     .before(<usage>),
+    /// )
+    ///
+    /// == Motivation
+    /// == Overview
+    ///
+    /// = Usage <usage>
+    /// == Setup
+    /// ```
     #[default(LocatableSelector(HeadingElem::elem().select()))]
     #[borrowed]
     pub target: LocatableSelector,
@@ -7,7 +7,7 @@ use crate::foundations::{
     Smart, Unlabellable, Value,
 };
 use crate::introspection::{Count, CounterUpdate, Locatable};
-use crate::layout::{Em, HAlignment, Length, OuterHAlignment};
+use crate::layout::{Em, HAlignment, Length, OuterHAlignment, Ratio};
 use crate::model::Numbering;
 
 /// A logical subdivison of textual content.
@@ -216,6 +216,46 @@ pub struct ParElem {
     #[resolve]
     pub hanging_indent: Length,
 
+    /// How much of the available width the last line of a paragraph should
+    /// fill at least.
+    ///
+    /// A last line that falls short of this is penalized like a
+    /// [runt]($text.costs), so that the line breaker prefers pulling another
+    /// word down into it. The strength of the penalty is thus configured
+    /// through the `runt` cost. This only has an effect with
+    /// [optimized line breaking]($par.linebreaks), and never affects
+    /// paragraphs that consist of a single line. Lines ending in a forced
+    /// [line break]($linebreak) within the paragraph are not affected either.
+    ///
+    /// ```example
+    /// #set par(justify: true, last-line-min-fill: 30%)
+    /// #lorem(21)
+    /// ```
+    #[default(Ratio::zero())]
+    pub last_line_min_fill: Ratio,
+
+    /// Whether to drop paragraphs that contain no visible content.
+    ///
+    /// Show rules that conditionally produce content can leave behind
+    /// paragraphs consisting only of spaces or empty text. When this is
+    /// enabled, such paragraphs are dropped entirely, so that they don't
+    /// leave a gap in the output. Introspectable elements like [metadata]
+    /// within them are kept.
+    ///
+    /// ```example
+    /// #let draft = false
+    /// #set par(drop-empty-from-show: true)
+    /// #show "TODO": it => if draft { it } else { "" }
+    ///
+    /// First paragraph.
+    ///
+    /// TODO
+    ///
+    /// Second paragraph.
+    /// ```
+    #[default(false)]
+    pub drop_empty_from_show: bool,
+
     /// The contents of the paragraph.
     #[required]
     pub body: Content,
@@ -68,6 +68,8 @@ pub struct QuoteElem {
     ///
     /// The double quotes used are inferred from the `quotes` property on
     /// [smartquote], which is affected by the `lang` property on [text].
+    /// Within a quote wrapped in double quotes, nested quotes as well as
+    /// double smart quotes (`"`) use single quotes instead, and vice versa.
     ///
     /// - `{true}`: Wrap this quote in double quotes.
     /// - `{false}`: Do not wrap this quote in double quotes.
@@ -137,6 +139,16 @@ pub struct QuoteElem {
     depth: Depth,
 }
 
+impl QuoteElem {
+    /// Whether content in the given style chain is enclosed in double
+    /// quotation marks that were added by a quote. Further nested quotation
+    /// marks should then use the single kind.
+    pub fn in_double_quotes(styles: StyleChain) -> bool {
+        let Depth(depth) = Self::depth_in(styles);
+        depth % 2 == 1
+    }
+}
+
 /// Attribution for a [quote](QuoteElem).
 #[derive(Debug, Clone, PartialEq, Hash)]
 pub enum Attribution {
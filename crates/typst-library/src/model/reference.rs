@@ -106,6 +106,31 @@ use crate::text::TextElem;
 /// In @beginning we prove @pythagoras.
 /// $ a^2 + b^2 = c^2 $ <pythagoras>
 /// ```
+///
+/// References are locatable, so you can [query] them to find out where an
+/// element is referenced. This way, an element can display its incoming
+/// references, for instance as a list of pages.
+///
+/// ```example
+/// #set heading(numbering: "1.")
+///
+/// #let referenced-on(target) = context {
+///   let pages = query(ref.where(target: target))
+///     .map(it => it.location().page())
+///     .dedup()
+///   if pages == () {
+///     [Not referenced.]
+///   } else {
+///     [Referenced on p. #pages.map(str).join(", ").]
+///   }
+/// }
+///
+/// = Methods <methods>
+/// _#referenced-on(<methods>)_
+///
+/// = Results
+/// As described in @methods, ...
+/// ```
 #[elem(title = "Reference", Synthesize, Locatable, Show)]
 pub struct RefElem {
     /// The target label that should be referenced.
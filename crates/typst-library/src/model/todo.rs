@@ -0,0 +1,88 @@
+use crate::diag::{warning, SourceResult};
+use crate::engine::Engine;
+use crate::foundations::{
+    elem, Content, NativeElement, Packed, Show, StyleChain, Synthesize,
+};
+use crate::introspection::Locatable;
+use crate::text::HighlightElem;
+
+/// A marker for unfinished work.
+///
+/// Todos are highlighted in the output so that they stand out while writing.
+/// Since they are locatable, they can be [queried]($query) to build a summary
+/// of all the work that is left.
+///
+/// # Example
+/// ```example
+/// #set page(height: 100pt)
+///
+/// = Results
+/// #todo[Add the measurements.]
+///
+/// = Discussion
+/// #todo[Compare with prior work.]
+///
+/// #context [
+///   Open todos: #query(todo).len()
+/// ]
+/// ```
+#[elem(Locatable, Synthesize, Show)]
+pub struct TodoElem {
+    /// Describes the work that is left to do.
+    #[required]
+    pub body: Content,
+
+    /// Whether the number of unresolved todos should be reported as a warning
+    /// during compilation.
+    ///
+    /// Only todos for which this is enabled are counted, so you can turn it
+    /// on for a whole document with `{set todo(warn: true)}` while drafting.
+    /// The warning is emitted regardless of how todos are shown, so it also
+    /// appears with a custom show rule.
+    #[default(false)]
+    pub warn: bool,
+}
+
+impl Synthesize for Packed<TodoElem> {
+    fn synthesize(
+        &mut self,
+        engine: &mut Engine,
+        styles: StyleChain,
+    ) -> SourceResult<()> {
+        // The count is reported with the first todo that has warnings enabled,
+        // so that it is emitted exactly once. This happens here rather than in
+        // `show` so that it doesn't depend on which show rule applies.
+        if !self.warn(styles) {
+            return Ok(());
+        }
+
+        let reported: Vec<_> = engine
+            .introspector
+            .query(&TodoElem::elem().select())
+            .into_iter()
+            .filter(|todo| {
+                todo.to_packed::<TodoElem>().unwrap().warn(StyleChain::default())
+            })
+            .collect();
+
+        if reported.first().and_then(Content::location) == self.location() {
+            let count = reported.len();
+            engine.sink.warn(warning!(
+                self.span(),
+                "document contains {} unresolved {}",
+                count,
+                if count == 1 { "todo" } else { "todos" };
+                hint: "this is the first of them"
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl Show for Packed<TodoElem> {
+    #[typst_macros::time(name = "todo", span = self.span())]
+    fn show(&self, _: &mut Engine, _: StyleChain) -> SourceResult<Content> {
+        Ok(HighlightElem::new(self.body.clone()).pack().spanned(self.span()))
+    }
+}
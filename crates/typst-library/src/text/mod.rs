@@ -693,6 +693,29 @@ pub struct TextElem {
     #[ghost]
     pub fractions: bool,
 
+    /// Whether to replace hyphens with typographic dashes.
+    ///
+    /// When enabled, three consecutive hyphens turn into an em dash and two
+    /// into an en dash, just like the `---` and `--` shorthands in markup. A
+    /// hyphen between two numbers turns into an en dash, as is conventional
+    /// for number ranges. Hyphens in front of a number, like in `-5`, are
+    /// kept, and so are those in numbers with multiple hyphens, like the date
+    /// `2024-01-15`. Since markup has shorthands for the dashes, this is
+    /// mostly useful for text that comes from elsewhere, e.g. from a data
+    /// file. [Raw text]($raw) is never affected.
+    ///
+    /// The replacement happens during layout, so this setting has no effect
+    /// in HTML export.
+    ///
+    /// ```example
+    /// #let pages = "Pages 10-24 -- see also 1-3 (as of 2024-01-15)"
+    /// #pages \
+    /// #text(smart-dashes: true, pages)
+    /// ```
+    #[default(false)]
+    #[ghost]
+    pub smart_dashes: bool,
+
     /// Raw OpenType features to apply.
     ///
     /// - If given an array of strings, sets the features identified by the
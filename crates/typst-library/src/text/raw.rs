@@ -266,6 +266,30 @@ pub struct RawElem {
     #[default(2)]
     pub tab_size: usize,
 
+    /// Whether to merge this raw block with directly following raw blocks.
+    ///
+    /// When enabled, consecutive raw blocks that are only separated by spaces
+    /// or paragraph breaks and share the same language are displayed as one
+    /// continuous listing. Their lines are concatenated, so that line numbers
+    /// continue from one part to the next. Raw blocks with differing
+    /// languages are never merged and neither are inline raw texts.
+    ///
+    /// ````example
+    /// #set raw(merge: true)
+    /// #show raw.line: it => [#it.number. #it.body]
+    ///
+    /// ```rust
+    /// fn main() {
+    /// ```
+    ///
+    /// ```rust
+    ///     println!("Hello World!");
+    /// }
+    /// ```
+    /// ````
+    #[default(false)]
+    pub merge: bool,
+
     /// The stylized lines of raw text.
     ///
     /// Made accessible for the [`raw.line` element]($raw.line).
@@ -475,6 +499,7 @@ impl ShowSet for Packed<RawElem> {
         out.set(TextElem::set_overhang(false));
         out.set(TextElem::set_lang(Lang::ENGLISH));
         out.set(TextElem::set_hyphenate(Smart::Custom(false)));
+        out.set(TextElem::set_smart_dashes(false));
         out.set(TextElem::set_size(TextSize(Em::new(0.8).into())));
         out.set(TextElem::set_font(FontList(vec![FontFamily::new("DejaVu Sans Mono")])));
         out.set(TextElem::set_cjk_latin_spacing(Smart::Custom(None)));
@@ -507,6 +532,18 @@ pub enum RawContent {
 }
 
 impl RawContent {
+    /// Returns or synthesizes the lines of the raw text. Lines synthesized from
+    /// a plain string are all assigned the given span.
+    pub fn lines(&self, span: Span) -> EcoVec<(EcoString, Span)> {
+        match self {
+            RawContent::Text(text) => split_newlines(text)
+                .into_iter()
+                .map(|line| (line.into(), span))
+                .collect(),
+            RawContent::Lines(lines) => lines.clone(),
+        }
+    }
+
     /// Returns or synthesizes the text content of the raw text.
     fn get(&self) -> EcoString {
         match self.clone() {
@@ -11,12 +11,12 @@ use arrayvec::ArrayVec;
 use bumpalo::collections::{String as BumpString, Vec as BumpVec};
 use comemo::Track;
 use ecow::EcoString;
-use typst_library::diag::{bail, At, SourceResult};
+use typst_library::diag::{bail, warning, At, SourceResult};
 use typst_library::engine::Engine;
 use typst_library::foundations::{
-    Content, Context, ContextElem, Element, NativeElement, Recipe, RecipeIndex, Selector,
-    SequenceElem, Show, ShowSet, Style, StyleChain, StyledElem, Styles, SymbolElem,
-    Synthesize, Transformation,
+    Content, Context, ContextElem, Element, NativeElement, Recipe, RecipeIndex, Repr,
+    Selector, SequenceElem, Show, ShowSet, Smart, Style, StyleChain, StyledElem, Styles,
+    SymbolElem, Synthesize, Transformation,
 };
 use typst_library::html::{tag, HtmlElem};
 use typst_library::introspection::{Locatable, SplitLocator, Tag, TagElem};
@@ -25,11 +25,14 @@ use typst_library::layout::{
 };
 use typst_library::math::{EquationElem, Mathy};
 use typst_library::model::{
-    CiteElem, CiteGroup, DocumentElem, EnumElem, ListElem, ListItemLike, ListLike,
-    ParElem, ParbreakElem, TermsElem,
+    CiteElem, CiteGroup, DocumentElem, EnumElem, HeadingElem, ListElem, ListItemLike,
+    ListLike, ParElem, ParbreakElem, QuoteElem, Refable, TableElem, TermsElem,
 };
 use typst_library::routines::{Arenas, FragmentKind, Pair, RealizationKind};
-use typst_library::text::{LinebreakElem, SmartQuoteElem, SpaceElem, TextElem};
+use typst_library::text::{
+    LinebreakElem, RawContent, RawElem, SmartQuoteElem, SpaceElem, TextElem,
+};
+use typst_library::{Feature, World};
 use typst_syntax::Span;
 use typst_utils::{SliceExt, SmallBitSet};
 
@@ -43,6 +46,7 @@ pub fn realize<'a>(
     content: &'a Content,
     styles: StyleChain<'a>,
 ) -> SourceResult<Vec<Pair<'a>>> {
+    let tagged = engine.world.library().features.is_enabled(Feature::A11y);
     let mut s = State {
         engine,
         locator,
@@ -60,6 +64,8 @@ pub fn realize<'a>(
         outside: matches!(kind, RealizationKind::LayoutDocument(_)),
         may_attach: false,
         saw_parbreak: false,
+        tagged,
+        enum_resume: None,
         kind,
     };
 
@@ -101,6 +107,12 @@ struct State<'a, 'x, 'y, 'z> {
     may_attach: bool,
     /// Whether we visited any paragraph breaks.
     saw_parbreak: bool,
+    /// Whether structural elements should be located, so that the logical
+    /// structure of the document is recorded for accessible export.
+    tagged: bool,
+    /// The number that follows the last item of the most recent enum, unless
+    /// a paragraph or heading came after it.
+    enum_resume: Option<u64>,
 }
 
 /// Defines a rule for how certain elements shall be grouped during realization.
@@ -243,6 +255,18 @@ fn visit<'a>(
         return Ok(());
     }
 
+    // Smart quotes nested in a quotation need to alternate their kind. Needs
+    // to happen before show rules so that they see the effective kind.
+    if visit_quote_rules(s, content, styles)? {
+        return Ok(());
+    }
+
+    // Enums may continue the numbering of a preceding one. Needs to happen
+    // before show rules so that they see the effective start.
+    if visit_enum_rules(s, content, styles)? {
+        return Ok(());
+    }
+
     // Apply show rules and preparation.
     if visit_show_rules(s, content, styles)? {
         return Ok(());
@@ -251,8 +275,20 @@ fn visit<'a>(
     // Recurse into sequences. Styled elements and sequences can currently also
     // have labels, so this needs to happen before they are handled.
     if let Some(sequence) = content.to_packed::<SequenceElem>() {
-        for elem in &sequence.children {
-            visit(s, elem, styles)?;
+        let mut children = sequence.children.as_slice();
+        while let [elem, rest @ ..] = children {
+            // Consecutive raw blocks may be merged into one before show rules
+            // see them, so that they form a continuous listing.
+            children = match merge_raws(children, styles) {
+                Some((merged, rest)) => {
+                    visit(s, s.store(merged), styles)?;
+                    rest
+                }
+                None => {
+                    visit(s, elem, styles)?;
+                    rest
+                }
+            };
         }
         return Ok(());
     }
@@ -338,6 +374,121 @@ fn visit_math_rules<'a>(
     Ok(false)
 }
 
+/// Turns double smart quotes within a quotation that is itself wrapped in
+/// double quotes into single ones, matching how nested `quote` elements
+/// alternate.
+fn visit_quote_rules<'a>(
+    s: &mut State<'a, '_, '_, '_>,
+    content: &'a Content,
+    styles: StyleChain<'a>,
+) -> SourceResult<bool> {
+    let Some(elem) = content.to_packed::<SmartQuoteElem>() else {
+        return Ok(false);
+    };
+
+    if !elem.double(styles) || !QuoteElem::in_double_quotes(styles) {
+        return Ok(false);
+    }
+
+    let mut elem = elem.clone();
+    elem.push_double(false);
+    visit(s, s.store(elem.pack()), styles)?;
+    Ok(true)
+}
+
+/// Lets an enum continue the numbering of the previous one if it is only
+/// separated from it by blocks and continuation is enabled.
+fn visit_enum_rules<'a>(
+    s: &mut State<'a, '_, '_, '_>,
+    content: &'a Content,
+    styles: StyleChain<'a>,
+) -> SourceResult<bool> {
+    if content.is::<ParElem>() || content.is::<HeadingElem>() {
+        s.enum_resume = None;
+        return Ok(false);
+    }
+
+    let Some(elem) = content.to_packed::<EnumElem>() else {
+        return Ok(false);
+    };
+
+    if content.is_prepared() {
+        return Ok(false);
+    }
+
+    if let Some(next) = s.enum_resume.take() {
+        if elem.continue_over_blocks(styles)
+            && elem.start(styles).is_auto()
+            && !elem.reversed(styles)
+        {
+            let mut elem = elem.clone();
+            elem.push_start(Smart::Custom(next));
+            visit(s, s.store(elem.pack()), styles)?;
+            return Ok(true);
+        }
+    }
+
+    // Remember where a following enum would continue.
+    if !elem.reversed(styles) {
+        let mut number = elem.start(styles).unwrap_or(1);
+        for item in elem.children.iter() {
+            number = item.number(styles).unwrap_or(number).saturating_add(1);
+        }
+        s.enum_resume = Some(number);
+    }
+
+    Ok(false)
+}
+
+/// Merges a run of raw blocks at the start of `children` if the first one has
+/// merging enabled. Returns the merged raw element and the children following
+/// the run, or `None` if there was nothing to merge.
+fn merge_raws<'a>(
+    children: &'a [Content],
+    styles: StyleChain,
+) -> Option<(Content, &'a [Content])> {
+    let (first, rest) = children.split_first()?;
+    let raw = first.to_packed::<RawElem>()?;
+    if first.is_prepared() || !raw.block(styles) || !raw.merge(styles) {
+        return None;
+    }
+
+    let lang = raw.lang(styles);
+    let mut lines = raw.text.lines(raw.span());
+    let mut rest = rest;
+    let mut merged = false;
+
+    // Only spaces and paragraph breaks (i.e. blank lines) may separate the
+    // parts of a merged listing.
+    while let Some(i) = rest
+        .iter()
+        .position(|c| !c.is::<SpaceElem>() && !c.is::<ParbreakElem>())
+    {
+        let next = &rest[i];
+        let Some(elem) = next.to_packed::<RawElem>() else { break };
+        if next.is_prepared()
+            || next.label().is_some()
+            || !elem.block(styles)
+            || !elem.merge(styles)
+            || elem.lang(styles) != lang
+        {
+            break;
+        }
+
+        lines.extend(elem.text.lines(elem.span()));
+        rest = &rest[i + 1..];
+        merged = true;
+    }
+
+    if !merged {
+        return None;
+    }
+
+    let mut elem = raw.clone();
+    elem.text = RawContent::Lines(lines);
+    Some((elem.pack(), rest))
+}
+
 /// Tries to apply show rules to or prepare content. Returns `true` if the
 /// element was handled.
 fn visit_show_rules<'a>(
@@ -346,7 +497,8 @@ fn visit_show_rules<'a>(
     styles: StyleChain<'a>,
 ) -> SourceResult<bool> {
     // Determines whether and how to proceed with show rule application.
-    let Some(Verdict { prepared, mut map, step }) = verdict(s.engine, content, styles)
+    let Some(Verdict { prepared, mut map, step }) =
+        verdict(s.engine, content, styles, s.tagged)
     else {
         return Ok(false);
     };
@@ -358,7 +510,8 @@ fn visit_show_rules<'a>(
     // prepare it.
     let mut tags = None;
     if !prepared {
-        tags = prepare(s.engine, s.locator, output.to_mut(), &mut map, styles)?;
+        tags = prepare(s.engine, s.locator, output.to_mut(), &mut map, styles, s.tagged)?;
+        check_duplicate_label(s.engine, &output);
     }
 
     // Apply a show rule step, if there is one.
@@ -426,6 +579,7 @@ fn verdict<'a>(
     engine: &mut Engine,
     target: &'a Content,
     styles: StyleChain<'a>,
+    tagged: bool,
 ) -> Option<Verdict<'a>> {
     let prepared = target.is_prepared();
     let mut map = Styles::new();
@@ -505,6 +659,7 @@ fn verdict<'a>(
                 && !target.can::<dyn ShowSet>()
                 && !target.can::<dyn Locatable>()
                 && !target.can::<dyn Synthesize>()
+                && !(tagged && is_structural(target))
         })
     {
         return None;
@@ -520,16 +675,21 @@ fn prepare(
     target: &mut Content,
     map: &mut Styles,
     styles: StyleChain,
+    tagged: bool,
 ) -> SourceResult<Option<(Tag, Tag)>> {
     // Generate a location for the element, which uniquely identifies it in
     // the document. This has some overhead, so we only do it for elements
-    // that are explicitly marked as locatable and labelled elements.
+    // that are explicitly marked as locatable and labelled elements. For
+    // accessible export, structural elements are located as well, so that
+    // their start and end tags record the document's logical structure.
     //
     // The element could already have a location even if it is not prepared
     // when it stems from a query.
     let key = typst_utils::hash128(&target);
     if target.location().is_none()
-        && (target.can::<dyn Locatable>() || target.label().is_some())
+        && (target.can::<dyn Locatable>()
+            || target.label().is_some()
+            || (tagged && is_structural(target)))
     {
         let loc = locator.next_location(engine.introspector, key);
         target.set_location(loc);
@@ -568,6 +728,51 @@ fn prepare(
     Ok(tags)
 }
 
+/// Whether the element is part of the logical structure of the document that
+/// must be available to accessible export.
+///
+/// Since the resulting tags are emitted during realization, their nesting
+/// reflects the logical structure even for content that layout moves around,
+/// like floating figures.
+fn is_structural(content: &Content) -> bool {
+    let elem = content.elem();
+    elem == ParElem::elem()
+        || elem == ListElem::elem()
+        || elem == EnumElem::elem()
+        || elem == TermsElem::elem()
+        || elem == TableElem::elem()
+}
+
+/// Warns about a referenceable element whose label is also attached to another
+/// referenceable element in the document, since references to it are
+/// ambiguous.
+///
+/// This consults the introspector rather than the elements seen by this
+/// realization, so that duplicates in separately realized containers are found,
+/// too. Warnings from iterations before the document converged are discarded.
+fn check_duplicate_label(engine: &mut Engine, elem: &Content) {
+    let Some(label) = elem.label() else { return };
+    if !elem.can::<dyn Refable>() {
+        return;
+    }
+
+    let location = elem.location();
+    let duplicated = engine
+        .introspector
+        .query(&Selector::Label(label))
+        .iter()
+        .any(|other| other.can::<dyn Refable>() && other.location() != location);
+
+    if duplicated {
+        engine.sink.warn(warning!(
+            elem.span(),
+            "label `{}` occurs multiple times in the document",
+            label.repr();
+            hint: "references to this label will be ambiguous"
+        ));
+    }
+}
+
 /// Handles a styled element.
 fn visit_styled<'a>(
     s: &mut State<'a, '_, '_, '_>,
@@ -987,12 +1192,33 @@ fn finish_par(mut grouped: Grouped) -> SourceResult<()> {
     let span = select_span(elems);
     let (body, trunk) = repack(elems);
 
+    // If requested, drop paragraphs without any visible content, but keep
+    // their tags so that introspection still works.
+    if ParElem::drop_empty_from_show_in(trunk) && elems.iter().all(is_invisible) {
+        let tags = grouped.s.store_slice(elems);
+        let s = grouped.end();
+        for &(content, styles) in tags.iter().filter(|(c, _)| c.is::<TagElem>()) {
+            visit(s, content, styles)?;
+        }
+        return Ok(());
+    }
+
     // Create and visit the paragraph.
     let s = grouped.end();
     let elem = ParElem::new(body).pack().spanned(span);
     visit(s, s.store(elem), trunk)
 }
 
+/// Whether an inline-level element produces no visible output.
+fn is_invisible(&(content, styles): &Pair) -> bool {
+    content.is::<TagElem>()
+        || content.is::<SpaceElem>()
+        || content
+            .to_packed::<TextElem>()
+            .is_some_and(|elem| elem.text.is_empty())
+        || content.to_packed::<HElem>().is_some_and(|elem| elem.weak(styles))
+}
+
 /// Builds the `CiteGroup` from `CiteElem`s.
 fn finish_cites(grouped: Grouped) -> SourceResult<()> {
     // Collect the children.
@@ -1005,9 +1231,13 @@ fn finish_cites(grouped: Grouped) -> SourceResult<()> {
         .cloned()
         .collect();
 
-    // Create and visit the citation group.
+    // Create and visit the citation group. If requested, wrap it in a box so
+    // that it isn't broken across lines.
     let s = grouped.end();
-    let elem = CiteGroup::new(children).pack().spanned(span);
+    let mut elem = CiteGroup::new(children).pack().spanned(span);
+    if !s.kind.is_html() && CiteElem::group_unbreakable_in(trunk) {
+        elem = BoxElem::new().with_body(Some(elem)).pack().spanned(span);
+    }
     visit(s, s.store(elem), trunk)
 }
 
@@ -63,6 +63,7 @@ pub enum Attr {
     Html,
     Render,
     Large,
+    A11y,
 }
 
 /// The size of a file.
@@ -299,6 +300,7 @@ impl<'a> Parser<'a> {
                 "large" => Attr::Large,
                 "html" => Attr::Html,
                 "render" => Attr::Render,
+                "a11y" => Attr::A11y,
                 found => {
                     self.error(format!(
                         "expected attribute or closing ---, found `{found}`"
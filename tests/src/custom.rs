@@ -1,7 +1,9 @@
+use std::collections::HashMap;
 use std::fmt::Write;
 
 use typst::foundations::Smart;
-use typst::layout::PagedDocument;
+use typst::introspection::{Location, Tag};
+use typst::layout::{Frame, FrameItem, PagedDocument, Transform};
 use typst::model::DocumentInfo;
 use typst::World;
 
@@ -36,11 +38,155 @@ pub fn check(test: &Test, world: &TestWorld, doc: Option<&PagedDocument>) -> Str
             test_eq!(sink, info.author, ["Changed"]);
             test_eq!(sink, info.title.as_deref(), Some("Alternative"));
         }
+        "a11y-structure-tags" => {
+            let Some(doc) = doc else { return sink };
+            let tree = structure(&mut sink, doc);
+            for kind in ["par", "list", "enum", "terms", "table"] {
+                test_eq!(sink, tree.iter().any(|node| node.contains(kind)), true);
+            }
+            test_eq!(sink, tree.iter().any(|node| node.nests("figure", "table")), true);
+            test_eq!(sink, tree.iter().any(|node| node.nests("par", "figure")), false);
+
+            // Without the feature, paragraphs are not located and the visible
+            // output is the same.
+            let plain = world.clone().a11y(false);
+            let Ok(untagged) = typst::compile::<PagedDocument>(&plain).output else {
+                writeln!(sink, "failed to compile without a11y").unwrap();
+                return sink;
+            };
+            let tree = structure(&mut sink, &untagged);
+            test_eq!(sink, tree.iter().any(|node| node.contains("par")), false);
+            test_eq!(sink, visible(doc), visible(&untagged));
+        }
         _ => {}
     }
     sink
 }
 
+/// A node in the tree of elements formed by the start and end tags.
+struct Node {
+    name: &'static str,
+    children: Vec<Node>,
+}
+
+impl Node {
+    /// Whether this node or one of its descendants has the given name.
+    fn contains(&self, name: &str) -> bool {
+        self.name == name || self.children.iter().any(|child| child.contains(name))
+    }
+
+    /// Whether a node named `outer` has a descendant named `inner`.
+    fn nests(&self, outer: &str, inner: &str) -> bool {
+        (self.name == outer && self.children.iter().any(|child| child.contains(inner)))
+            || self.children.iter().any(|child| child.nests(outer, inner))
+    }
+}
+
+/// Builds the element tree from the tags in the document's frames, in the
+/// logical order also used by the introspector, and reports badly nested tags.
+fn structure(sink: &mut String, doc: &PagedDocument) -> Vec<Node> {
+    let mut tags = vec![];
+    let mut insertions = HashMap::new();
+    for page in &doc.pages {
+        collect_tags(&page.frame, &mut tags, &mut insertions);
+    }
+
+    let mut stack: Vec<(Location, Node)> = vec![];
+    let mut roots = vec![];
+    for tag in expand(tags, &mut insertions) {
+        match tag {
+            Tag::Start(elem) => {
+                let node = Node { name: elem.elem().name(), children: vec![] };
+                stack.push((elem.location().unwrap(), node));
+            }
+            Tag::End(loc, _) => {
+                let Some((start, node)) = stack.pop() else {
+                    writeln!(sink, "end tag without start tag").unwrap();
+                    continue;
+                };
+                if start != loc {
+                    writeln!(sink, "end tag of `{}` does not match", node.name).unwrap();
+                }
+                match stack.last_mut() {
+                    Some((_, parent)) => parent.children.push(node),
+                    None => roots.push(node),
+                }
+            }
+        }
+    }
+
+    for (_, node) in stack {
+        writeln!(sink, "start tag of `{}` without end tag", node.name).unwrap();
+    }
+
+    roots
+}
+
+/// Collects the tags of a frame, setting aside those in frames with a logical
+/// parent.
+fn collect_tags(
+    frame: &Frame,
+    tags: &mut Vec<Tag>,
+    insertions: &mut HashMap<Location, Vec<Tag>>,
+) {
+    for (_, item) in frame.items() {
+        match item {
+            FrameItem::Group(group) => match group.parent {
+                Some(parent) => {
+                    let mut nested = vec![];
+                    collect_tags(&group.frame, &mut nested, insertions);
+                    insertions.entry(parent).or_default().extend(nested);
+                }
+                None => collect_tags(&group.frame, tags, insertions),
+            },
+            FrameItem::Tag(tag) => tags.push(tag.clone()),
+            _ => {}
+        }
+    }
+}
+
+/// Places the set-aside tags right after the start tags of their parents.
+fn expand(tags: Vec<Tag>, insertions: &mut HashMap<Location, Vec<Tag>>) -> Vec<Tag> {
+    let mut out = vec![];
+    for tag in tags {
+        let inserted = match &tag {
+            Tag::Start(elem) => insertions.remove(&elem.location().unwrap()),
+            Tag::End(..) => None,
+        };
+        out.push(tag);
+        if let Some(inserted) = inserted {
+            out.extend(expand(inserted, insertions));
+        }
+    }
+    out
+}
+
+/// The page sizes and visible items of a document with absolute positions,
+/// ignoring tags and the groups that only hold tags.
+fn visible(doc: &PagedDocument) -> Vec<String> {
+    fn walk(frame: &Frame, ts: Transform, out: &mut Vec<String>) {
+        for (pos, item) in frame.items() {
+            match item {
+                FrameItem::Group(group) => {
+                    let ts = ts
+                        .pre_concat(Transform::translate(pos.x, pos.y))
+                        .pre_concat(group.transform);
+                    walk(&group.frame, ts, out);
+                }
+                FrameItem::Tag(_) => {}
+                item => out.push(format!("{:?} {item:?}", pos.transform(ts))),
+            }
+        }
+    }
+
+    let mut out = vec![];
+    for page in &doc.pages {
+        out.push(format!("page {:?}", page.frame.size()));
+        walk(&page.frame, Transform::identity(), &mut out);
+    }
+    out
+}
+
 /// Extract the document information.
 fn info(doc: Option<&PagedDocument>) -> DocumentInfo {
     doc.map(|doc| doc.info.clone()).unwrap_or_default()
@@ -46,7 +46,8 @@ impl<'a> Runner<'a> {
     fn new(test: &'a Test) -> Self {
         Self {
             test,
-            world: TestWorld::new(test.source.clone()),
+            world: TestWorld::new(test.source.clone())
+                .a11y(test.attrs.contains(&Attr::A11y)),
             seen: vec![false; test.notes.len()],
             result: TestResult {
                 errors: String::new(),
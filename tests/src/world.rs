@@ -26,6 +26,7 @@ use typst::{Feature, Library, World};
 pub struct TestWorld {
     main: Source,
     base: &'static TestBase,
+    a11y: bool,
 }
 
 impl TestWorld {
@@ -37,13 +38,24 @@ impl TestWorld {
         Self {
             main: source,
             base: singleton!(TestBase, TestBase::default()),
+            a11y: false,
         }
     }
+
+    /// Whether to enable the accessibility feature, which additionally
+    /// locates structural elements.
+    pub fn a11y(self, enabled: bool) -> Self {
+        Self { a11y: enabled, ..self }
+    }
 }
 
 impl World for TestWorld {
     fn library(&self) -> &LazyHash<Library> {
-        &self.base.library
+        if self.a11y {
+            &self.base.a11y_library
+        } else {
+            &self.base.library
+        }
     }
 
     fn book(&self) -> &LazyHash<FontBook> {
@@ -89,6 +101,7 @@ impl TestWorld {
 /// Shared foundation of all test worlds.
 struct TestBase {
     library: LazyHash<Library>,
+    a11y_library: LazyHash<Library>,
     book: LazyHash<FontBook>,
     fonts: Vec<Font>,
     slots: Mutex<HashMap<FileId, FileSlot>>,
@@ -102,7 +115,8 @@ impl Default for TestBase {
             .collect();
 
         Self {
-            library: LazyHash::new(library()),
+            library: LazyHash::new(library(&[Feature::Html])),
+            a11y_library: LazyHash::new(library(&[Feature::Html, Feature::A11y])),
             book: LazyHash::new(FontBook::from_fonts(&fonts)),
             fonts,
             slots: Mutex::new(HashMap::new()),
@@ -176,12 +190,12 @@ fn read(path: &Path) -> FileResult<Cow<'static, [u8]>> {
 }
 
 /// The extended standard library for testing.
-fn library() -> Library {
+fn library(features: &[Feature]) -> Library {
     // Set page width to 120pt with 10pt margins, so that the inner page is
     // exactly 100pt wide. Page height is unbounded and font size is 10pt so
     // that it multiplies to nice round numbers.
     let mut lib = Library::builder()
-        .with_features([Feature::Html].into_iter().collect())
+        .with_features(features.iter().copied().collect())
         .build();
 
     // Hook up helpers into the global scope.
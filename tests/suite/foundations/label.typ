@@ -92,3 +92,35 @@ _Visible_
 --- label-non-existent-error ---
 // Error: 5-10 sequence does not have field "label"
 #[].label
+
+--- label-duplicate-referenceable-warn ---
+#show heading: none
+
+// Warning: 1-4 label `<a>` occurs multiple times in the document
+// Hint: 1-4 references to this label will be ambiguous
+= A <a>
+// Warning: 1-4 label `<a>` occurs multiple times in the document
+// Hint: 1-4 references to this label will be ambiguous
+= B <a>
+
+// Labels produced by show rules are checked, too.
+// Warning: 14-24 label `<b>` occurs multiple times in the document
+// Hint: 14-24 references to this label will be ambiguous
+#show "x": [#heading[C] <b>]
+x x
+
+// Elements that can't be referenced may share a label.
+#metadata(1) <m>
+#metadata(2) <m>
+
+--- label-duplicate-separate-containers-warn ---
+// Duplicates are found even if they are realized separately. The containers
+// are placed so that they take up no space.
+#show heading: none
+
+// Warning: 14-17 label `<c>` occurs multiple times in the document
+// Hint: 14-17 references to this label will be ambiguous
+#place(block[= A <c>])
+// Warning: 16-26 label `<c>` occurs multiple times in the document
+// Hint: 16-26 references to this label will be ambiguous
+#place(hide[- #heading[B] <c>])
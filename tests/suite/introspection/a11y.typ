@@ -0,0 +1,20 @@
+// Test the tags emitted with the accessibility feature.
+
+--- a11y-structure-tags a11y ---
+// Checked by a custom check in `tests/src/custom.rs`. The content is hidden
+// and placed so that it takes up no space.
+#place(hide[
+  An introductory paragraph.
+
+  #figure(
+    placement: top,
+    table(columns: 2, [A], [B]),
+    caption: [A floating table],
+  )
+
+  After the figure.
+
+  - A list item
+  + An enum item
+  / Term: A description
+])
@@ -16,7 +16,11 @@
 #context locate(<intro>)
 
 --- locate-duplicate-label ---
+// Warning: 1-15 label `<intro>` occurs multiple times in the document
+// Hint: 1-15 references to this label will be ambiguous
 = Introduction <intro>
+// Warning: 1-15 label `<intro>` occurs multiple times in the document
+// Hint: 1-15 references to this label will be ambiguous
 = Introduction <intro>
 
 // Error: 10-25 label `<intro>` occurs multiple times in the document
@@ -32,7 +36,11 @@
 #context locate(heading)
 
 --- locate-element-selector-multiple-matches ---
+// Warning: 1-15 label `<intro>` occurs multiple times in the document
+// Hint: 1-15 references to this label will be ambiguous
 = Introduction <intro>
+// Warning: 1-15 label `<intro>` occurs multiple times in the document
+// Hint: 1-15 references to this label will be ambiguous
 = Introduction <intro>
 
 // Error: 10-25 selector matches multiple elements
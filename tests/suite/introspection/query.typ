@@ -79,7 +79,11 @@
 #heading(level: 3, outlined: false)[D]
 = E <first>
 #figure([Frog], kind: "frog", supplement: none)
+// Warning: 2-54 label `<second>` occurs multiple times in the document
+// Hint: 2-54 references to this label will be ambiguous
 #figure([Giraffe], kind: "giraffe", supplement: none) <second>
+// Warning: 2-56 label `<second>` occurs multiple times in the document
+// Hint: 2-56 references to this label will be ambiguous
 #figure([GiraffeCat], kind: "cat", supplement: [Other]) <second>
 = H
 #figure([Iguana], kind: "iguana", supplement: none)
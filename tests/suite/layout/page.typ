@@ -348,6 +348,33 @@ A
   A
 ]
 
+--- page-overfull-tolerance-warn ---
+// The word is 120.41pt wide, so it exceeds the 100pt wide page body by
+// 20.41pt. It is hidden and placed so that it takes up no space.
+#set page(overfull-tolerance: 1pt)
+#set text(font: "DejaVu Sans Mono", size: 10pt, hyphenate: false)
+// Warning: 13-33 line exceeds the available width by 20.41pt
+// Hint: 13-33 enable hyphenation or rephrase the text so that it can be broken
+#place(hide[aaaaaaaaaaaaaaaaaaaa])
+
+--- page-overfull-tolerance-within ---
+// Lines that exceed the available width by less than the tolerance are fine.
+#set page(overfull-tolerance: 30pt)
+#set text(font: "DejaVu Sans Mono", size: 10pt, hyphenate: false)
+#place(hide[aaaaaaaaaaaaaaaaaaaa])
+
+--- page-overfull-tolerance-none ---
+// Overfull lines are not reported by default.
+#set text(font: "DejaVu Sans Mono", size: 10pt, hyphenate: false)
+#place(hide[aaaaaaaaaaaaaaaaaaaa])
+
+--- page-overfull-tolerance-auto-width ---
+// Lines in regions of automatic width, like during measurement, can't be
+// overfull.
+#set page(overfull-tolerance: 1pt)
+#set text(font: "DejaVu Sans Mono", size: 10pt, hyphenate: false)
+#context test(measure[aaaaaaaaaaaaaaaaaaaa].width > 100pt, true)
+
 --- issue-2631-page-header-ordering ---
 #set text(6pt)
 #show heading: set text(6pt, weight: "regular")
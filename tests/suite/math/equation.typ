@@ -11,6 +11,24 @@ $ phi.alt := (1 + sqrt(5)) / 2 $ <ratio>
 With @ratio, we get
 $ F_n = round(1 / sqrt(5) phi.alt^n) $ <fib>
 
+--- math-equation-number-referenced-only ---
+#set math.equation(numbering: "(1)", number-referenced-only: true)
+#show math.equation: none
+#show ref: none
+
+// Forward references count, too.
+@second
+
+$ a $ <first>
+$ b $ <second>
+$ c $
+
+#context test(
+  query(math.equation).map(it => it.numbering != none),
+  (false, true, false),
+)
+#context test(counter(math.equation).at(<second>), (1,))
+
 --- math-equation-font ---
 // Test different font.
 #show math.equation: set text(font: "Fira Math")
@@ -31,6 +31,17 @@ And quark! @quark
 Now we have multiple bibliographies containing @glacier-melt @keshav2007read
 #bibliography(("/assets/bib/works.bib", "/assets/bib/works_too.bib"))
 
+--- bibliography-multiple-scoped ---
+// Each bibliography covers the citations since the previous one.
+#show bibliography: none
+
+// Error: 1-16 key `keshav2007read` does not exist in the bibliography
+@keshav2007read
+#bibliography("/assets/bib/works.bib")
+
+@keshav2007read
+#bibliography("/assets/bib/works_too.bib")
+
 --- bibliography-duplicate-key ---
 // Error: 15-65 duplicate bibliography keys: netwok, issue201, arrgh, quark, distress, glacier-melt, tolkien54, DBLP:books/lib/Knuth86a, sharing, restful, mcintosh_anxiety, psychology25
 #bibliography(("/assets/bib/works.bib", "/assets/bib/works.bib"))
@@ -49,6 +49,22 @@ A @netwok @arrgh @quark, B.
 #show bibliography: none
 #bibliography("/assets/bib/works.bib", style: "american-physics-society")
 
+--- cite-group-unbreakable ---
+// Test that unbreakable citation groups are wrapped in a box. The citations
+// are placed so that they take up no space.
+#set cite(group-unbreakable: true)
+#show box: metadata(none)
+
+#place[
+  @netwok @arrgh \
+  @quark
+]
+
+#show bibliography: none
+#bibliography("/assets/bib/works.bib")
+
+#context test(query(metadata).len(), 2)
+
 --- issue-785-cite-locate ---
 // Test citation in other introspection.
 #set page(width: 180pt)
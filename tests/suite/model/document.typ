@@ -22,6 +22,42 @@ What's up?
 Hello
 #set document(title: [Hello])
 
+--- document-page-count-multiple-of ---
+// The document is padded with blank pages up to a multiple of four.
+#set document(page-count: (multiple-of: 4))
+#pagebreak()
+#context test(counter(page).final(), (4,))
+
+--- document-page-count-multiple-of-exact ---
+// No padding is needed if the page count already is a multiple.
+#set document(page-count: (multiple-of: 2))
+#pagebreak()
+#context test(counter(page).final(), (2,))
+
+--- document-page-count-multiple-of-parity ---
+// The padding happens after pages inserted to reach a page parity.
+#set document(page-count: (multiple-of: 4))
+#pagebreak(to: "odd")
+#metadata(none) <third>
+#context test(locate(<third>).page(), 3)
+#context test(counter(page).final(), (4,))
+
+--- document-page-count-multiple-of-trailing-tags ---
+// Tags after the last page run stay on the last page with content instead of
+// moving to the padding.
+#set document(page-count: (multiple-of: 4))
+#place(hide[First])
+#pagebreak(weak: true)
+#metadata(none) <end>
+#context test(locate(<end>).page(), 1)
+#context test(counter(page).final(), (4,))
+
+--- document-page-count-max ---
+// Warning: document has 2 pages, more than the maximum of 1
+// Hint: this maximum comes from `document(page-count: ..)` and does not prevent export
+#set document(page-count: (max: 1))
+#pagebreak()
+
 --- document-constructor ---
 // Error: 2-12 can only be used in set rules
 #document()
@@ -188,6 +188,30 @@ a + 0.
 #set enum(number-align: horizon)
 #set enum(number-align: bottom)
 
+--- enum-continue-over-blocks ---
+#set enum(continue-over-blocks: true)
+#show enum: it => metadata(it.at("start", default: auto))
+#show figure: none
+#show par: none
+
++ A
++ B
+
+#figure[]
+
++ C
+2. D
+
+#figure[]
+
++ E
+
+Text
+
++ F
+
+#context test(query(metadata).map(m => m.value), (auto, 3, 3, auto))
+
 --- enum-par render html ---
 // Check whether the contents of enum items become paragraphs.
 #show par: it => if target() != "html" { highlight(it) } else { it }
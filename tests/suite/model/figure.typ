@@ -116,6 +116,8 @@ We can clearly see that @fig-cylinder and
 }
 
 #set page(width: 150pt)
+// Warning: 1:2-7:2 label `<fig-formula>` occurs multiple times in the document
+// Hint: 1:2-7:2 references to this label will be ambiguous
 #figure(
   $a^2 + b^2 = c^2$,
   supplement: "Theorem",
@@ -124,6 +126,8 @@ We can clearly see that @fig-cylinder and
   numbering: "1",
 ) <fig-formula>
 
+// Warning: 1:2-7:2 label `<fig-formula>` occurs multiple times in the document
+// Hint: 1:2-7:2 references to this label will be ambiguous
 #figure(
   $a^2 + b^2 = c^2$,
   supplement: "Theorem",
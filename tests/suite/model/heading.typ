@@ -133,6 +133,29 @@ Cannot be used as @intro
 #show par: highlight
 = Heading
 
+--- heading-max-level ---
+#set heading(max-level: 3)
+#show heading: none
+// Warning: 1-11 heading of level 5 exceeds the maximum level of 3
+// Hint: 1-11 the heading was clamped to level 3
+===== Deep
+#context test(query(heading).map(it => it.level), (3,))
+
+--- heading-max-level-show-rule ---
+// Headings produced by show rules are checked, too.
+#set heading(max-level: 1)
+// Warning: 21-42 heading of level 2 exceeds the maximum level of 1
+// Hint: 21-42 the heading was clamped to level 1
+#show "deep": it => heading(depth: 2, it)
+#show heading: none
+deep
+
+--- heading-max-level-error ---
+#set heading(max-level: 2, level-overflow: "error")
+// Error: 1-10 heading of level 3 exceeds the maximum level of 2
+// Hint: 1-10 you may want to restructure your document so that it doesn't contain deep headings
+=== Deep
+
 --- heading-html-basic html ---
 // level 1 => h2
 // ...
@@ -235,6 +235,29 @@ Welcome \ here. Does this work well?
 لآن وقد أظلم الليل وبدأت النجوم
 تنضخ وجه الطبيعة التي أعْيَتْ من طول ما انبعثت في النهار
 
+--- par-last-line-min-fill ---
+// The minimum fill pulls a word from the previous line down into the last one.
+// Both paragraphs are hidden and placed at the top of the 100pt wide page
+// body, so that they take up no space and start at the same position.
+#set par(justify: true, linebreaks: "optimized")
+#set text(costs: (runt: 10000%))
+
+#let word = box(width: 10pt, height: 1em)
+#let marked = box(width: 10pt, height: 1em, metadata(none))
+#let words = ((word,) * 15 + (marked,) + (word,) * 4).join[ ]
+
+#place(hide(par(last-line-min-fill: 0%, words)))
+#place(hide(par(last-line-min-fill: 60%, words)))
+
+#context {
+  let (without, with) = query(metadata).map(m => m.location().position())
+  // Without the minimum fill, the marked word ends the second line.
+  test(without.x > 10pt, true)
+  // With it, the marked word starts the last line.
+  test(with.x, 10pt)
+  test(with.y > without.y, true)
+}
+
 --- par-trailing-whitespace ---
 // Ensure that trailing whitespace layouts as intended.
 #box(fill: aqua, " ")
@@ -260,6 +283,38 @@ Welcome \ here. Does this work well?
 #block(height: 0pt)[#""#metadata(false)<hi>]
 #context test(query(<hi>).first().value, false)
 
+--- par-drop-empty-from-show ---
+// Paragraphs that end up empty due to show rules are dropped, but their
+// metadata survives.
+#set par(drop-empty-from-show: true)
+#show par: it => metadata("par")
+#show "TODO": ""
+
+A
+
+TODO
+
+#metadata("kept") TODO
+
+B
+
+#context test(
+  query(metadata).map(m => m.value),
+  ("par", "kept", "par"),
+)
+
+--- par-drop-empty-from-show-disabled ---
+#show par: it => metadata("par")
+#show "TODO": ""
+
+A
+
+TODO
+
+B
+
+#context test(query(metadata).len(), 3)
+
 --- par-metadata-after-trimmed-space ---
 // Ensure that metadata doesn't prevent trailing spaces from being trimmed.
 #set par(justify: true, linebreaks: "simple")
@@ -85,6 +85,19 @@ And I quote: #quote(attribution: [René Descartes])[cogito, ergo sum].
 #set smartquote(quotes: (single: ("<", ">"), double: ("(", ")")))
 #quote[A #quote[nested] quote]
 
+--- quote-nesting-smartquote ---
+// Double smart quotes in a quotation alternate to single ones.
+// The quotes are hidden and placed so that they take up no space.
+#show smartquote: it => metadata(it.double)
+#place(hide[
+  #quote["A" #quote["B" #quote["C"]]]
+  #quote(quotes: false)["D"]
+])
+#context test(
+  query(metadata).map(m => m.value),
+  (false, false, true, true, false, false, true, true),
+)
+
 --- quote-plato html ---
 #set quote(block: true)
 
@@ -14,7 +14,11 @@ As seen in @intro, we proceed.
 @foo
 
 --- ref-label-duplicate ---
+// Warning: 1-8 label `<foo>` occurs multiple times in the document
+// Hint: 1-8 references to this label will be ambiguous
 = First <foo>
+// Warning: 1-9 label `<foo>` occurs multiple times in the document
+// Hint: 1-9 references to this label will be ambiguous
 = Second <foo>
 
 // Error: 1-5 label `<foo>` occurs multiple times in the document
@@ -0,0 +1,29 @@
+// Test todo markers.
+
+--- todo-query ---
+#show todo: none
+#todo[A]#todo[B]
+
+// Todos produced by show rules are collected, too.
+#show "fixme": it => todo(it)
+fixme
+
+#context test(query(todo).map(it => it.body), ([A], [B], [fixme]))
+
+--- todo-warn ---
+#set todo(warn: true)
+#show todo: none
+// Warning: 2-9 document contains 2 unresolved todos
+// Hint: 2-9 this is the first of them
+#todo[A]#todo(warn: false)[B]#todo[C]
+
+--- todo-warn-custom-show-rule ---
+// The warning doesn't depend on the built-in show rule. The todos are hidden
+// and placed so that they take up no space.
+#set todo(warn: true)
+#show todo: it => box(fill: red, it.body)
+#place(hide[
+  // Warning: 4-11 document contains 1 unresolved todo
+  // Hint: 4-11 this is the first of them
+  #todo[A]
+])
@@ -708,6 +708,42 @@ a b c --------------------
 #let foo = "bar"
 ```
 
+--- raw-merge ---
+// Test that consecutive raw blocks in the same language are merged.
+#set raw(merge: true)
+#show raw: it => metadata(it.lines.map(line => (line.number, line.text)))
+
+```rust
+fn main() {
+```
+
+```rust
+}
+```
+
+```py
+pass
+```
+
+#context test(
+  query(metadata).map(m => m.value),
+  (((1, "fn main() {"), (2, "}")), ((1, "pass"),)),
+)
+
+--- raw-merge-disabled ---
+// Test that raw blocks are not merged by default.
+#show raw: it => metadata(it.lines.len())
+
+```rust
+fn main() {
+```
+
+```rust
+}
+```
+
+#context test(query(metadata).map(m => m.value), (1, 1))
+
 --- raw-unclosed ---
 // Test unterminated raw text.
 //
@@ -0,0 +1,43 @@
+// Test smart dashes. Since they are applied during layout, we compare the
+// widths of the laid out text.
+
+--- smartdash-ranges ---
+#let same(input, output) = context test(
+  measure(text(smart-dashes: true, input)).width,
+  measure(output).width,
+)
+
+// A hyphen between two numbers is an en dash.
+#same("10-24", "10–24")
+#same("pages 1-3 and 7-9", "pages 1–3 and 7–9")
+
+// Hyphens in front of numbers and between words are kept.
+#same("-5", "-5")
+#same("from -5 to 5", "from -5 to 5")
+#same("well-known", "well-known")
+
+// Numbers with multiple hyphens are kept.
+#same("2024-01-15", "2024-01-15")
+#same("555-123-4567", "555-123-4567")
+
+--- smartdash-double-and-triple ---
+#let same(input, output) = context test(
+  measure(text(smart-dashes: true, input)).width,
+  measure(output).width,
+)
+
+// Like the markup shorthands, two hyphens are an en dash and three are an em
+// dash.
+#same("a -- b", "a – b")
+#same("a --- b", "a — b")
+#same("1--2", "1–2")
+#same("a----b", "a—-b")
+
+--- smartdash-raw ---
+// Raw text is never affected. We use a proportional font to make the
+// difference measurable.
+#show raw: set text(font: "Libertinus Serif")
+#context test(
+  measure(text(smart-dashes: true, raw("10-24 -- a --- b"))).width,
+  measure(raw("10-24 -- a --- b")).width,
+)